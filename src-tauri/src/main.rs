@@ -5,7 +5,7 @@ use portable_pty::{native_pty_system, CommandBuilder, PtyPair, PtySize};
 use std::{
     collections::HashMap,
     io::{Read, Write},
-    sync::Arc,
+    sync::{Arc, Mutex},
     thread,
 };
 
@@ -15,7 +15,137 @@ use tokio::sync::mpsc;
 struct PtyInstance {
     pty_pair: PtyPair,
     writer: Box<dyn Write + Send>,
-    data_receiver: Arc<AsyncMutex<mpsc::Receiver<String>>>,
+    current_cwd: Arc<Mutex<String>>,
+}
+
+/// Payload emitted on the `pty-data://<pty_id>` channel for every (possibly batched) chunk of
+/// output a PTY produces.
+#[derive(Clone, serde::Serialize)]
+struct PtyDataEvent {
+    pty_id: String,
+    data: String,
+}
+
+/// Payload emitted on `pty-exited`, carrying the real exit status instead of just the pty_id so
+/// the frontend can tell a clean exit from a nonzero one or a crash.
+#[derive(Clone, serde::Serialize)]
+struct PtyExitedEvent {
+    pty_id: String,
+    exit_code: Option<i32>,
+    signal: Option<i32>,
+}
+
+/// Maps the signal names `portable_pty::ExitStatus` reports back to POSIX signal numbers.
+fn signal_number(name: &str) -> Option<i32> {
+    match name {
+        "SIGHUP" => Some(1),
+        "SIGINT" => Some(2),
+        "SIGQUIT" => Some(3),
+        "SIGILL" => Some(4),
+        "SIGABRT" => Some(6),
+        "SIGFPE" => Some(8),
+        "SIGKILL" => Some(9),
+        "SIGSEGV" => Some(11),
+        "SIGPIPE" => Some(13),
+        "SIGALRM" => Some(14),
+        "SIGTERM" => Some(15),
+        _ => None,
+    }
+}
+
+/// OSC 7 is the "report current directory" escape sequence: shells emit it on every prompt as
+/// `ESC ] 7 ; file://<hostname>/<url-encoded-path>` terminated by BEL or ST (`ESC \`).
+const OSC7_PREFIX: &str = "\x1b]7;";
+
+/// Scans `data` for complete OSC 7 sequences and returns the last reported cwd (if any) together
+/// with any unterminated OSC 7 sequence (or partial OSC 7 prefix) at the end of `data`, so the
+/// caller can carry it over into the next read in case a sequence straddles two reads.
+fn scan_osc7_cwd(data: &str) -> (Option<String>, String) {
+    let mut last_cwd = None;
+    let mut search_start = 0;
+    // Bytes to carry into the next read. Defaults to "nothing" - only an actual unterminated
+    // OSC 7 sequence (or a partial prefix right at the end) should grow this, otherwise output
+    // with no OSC 7 in it at all would have its entire chunk re-scanned forever.
+    let mut carry_start = data.len();
+
+    while let Some(rel_start) = data[search_start..].find(OSC7_PREFIX) {
+        let start = search_start + rel_start;
+        let payload_start = start + OSC7_PREFIX.len();
+
+        let bel = data[payload_start..].find('\x07').map(|i| (payload_start + i, payload_start + i + 1));
+        let st = data[payload_start..].find("\x1b\\").map(|i| (payload_start + i, payload_start + i + 2));
+        let terminator = match (bel, st) {
+            (Some(b), Some(s)) => Some(if b.0 < s.0 { b } else { s }),
+            (Some(b), None) => Some(b),
+            (None, Some(s)) => Some(s),
+            (None, None) => None,
+        };
+
+        let Some((payload_end, seq_end)) = terminator else {
+            // Incomplete sequence at the end of this chunk - carry only from its start, not
+            // everything that came before it.
+            carry_start = start;
+            break;
+        };
+
+        if let Some(path) = decode_osc7_path(&data[payload_start..payload_end]) {
+            last_cwd = Some(path);
+        }
+        search_start = seq_end;
+        carry_start = data.len();
+    }
+
+    // A prefix of OSC7_PREFIX itself (e.g. just "\x1b]7" with no ';' yet) can also straddle two
+    // reads; `find` above only matches the full prefix, so check for that separately.
+    if carry_start == data.len() {
+        if let Some(partial_start) = trailing_partial_osc7_prefix(&data[search_start..]) {
+            carry_start = search_start + partial_start;
+        }
+    }
+
+    (last_cwd, data[carry_start..].to_string())
+}
+
+/// Returns the start index (relative to `tail`) of a trailing prefix of `OSC7_PREFIX` (e.g.
+/// `"\x1b"`, `"\x1b]"`, `"\x1b]7"`) sitting right at the end of `tail`, if any.
+fn trailing_partial_osc7_prefix(tail: &str) -> Option<usize> {
+    let bytes = tail.as_bytes();
+    for len in (1..OSC7_PREFIX.len()).rev() {
+        if bytes.len() < len {
+            continue;
+        }
+        let candidate_start = bytes.len() - len;
+        if tail.is_char_boundary(candidate_start) && &bytes[candidate_start..] == OSC7_PREFIX[..len].as_bytes() {
+            return Some(candidate_start);
+        }
+    }
+    None
+}
+
+/// Extracts and URL-decodes the path portion of an OSC 7 payload (`file://<hostname>/<path>`).
+fn decode_osc7_path(payload: &str) -> Option<String> {
+    let rest = payload.strip_prefix("file://")?;
+    let path_start = rest.find('/')?;
+    Some(percent_decode(&rest[path_start..]))
+}
+
+/// Minimal `%XX` percent-decoder, sufficient for the paths shells put in OSC 7 sequences.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() && s.is_char_boundary(i + 3) {
+            if let Ok(byte) = u8::from_str_radix(&s[i + 1..i + 3], 16) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
 }
 
 struct AppState {
@@ -23,10 +153,11 @@ struct AppState {
     app_handle: AppHandle,
 }
 
-#[tauri::command]
-async fn async_create_shell(shell_path: Option<String>, rows: Option<u16>, cols: Option<u16>, state: State<'_, AppState>) -> Result<String, String> {
+/// Opens a new PTY, spawns `cmd` in it, and wires up the shared reader/cwd-tracking/exit-watcher
+/// threads. Used by every command that creates a PTY, whatever ends up in `cmd`.
+async fn spawn_pty(cmd: CommandBuilder, rows: Option<u16>, cols: Option<u16>, state: &State<'_, AppState>) -> Result<String, String> {
     let pty_system = native_pty_system();
-    
+
     let pty_pair = pty_system
         .openpty(PtySize {
             rows: rows.unwrap_or(24),
@@ -39,27 +170,6 @@ async fn async_create_shell(shell_path: Option<String>, rows: Option<u16>, cols:
     let reader = pty_pair.master.try_clone_reader().map_err(|err| err.to_string())?;
     let writer = pty_pair.master.take_writer().map_err(|err| err.to_string())?;
 
-    let mut cmd = match shell_path {
-        Some(path) => CommandBuilder::new(path),
-        None => {
-            #[cfg(target_os = "windows")]
-            { CommandBuilder::new("powershell.exe") }
-            #[cfg(not(target_os = "windows"))]
-            { CommandBuilder::new("bash") }
-        }
-    };
-
-    #[cfg(target_os = "windows")]
-    cmd.env("TERM", "cygwin");
-
-    #[cfg(not(target_os = "windows"))]
-    {
-        cmd.env("TERM", "xterm-256color");
-        // Set UTF-8 locale for proper Cyrillic and other non-ASCII character support
-        cmd.env("LANG", "en_US.UTF-8");
-        cmd.env("LC_ALL", "en_US.UTF-8");
-    }
-
     let mut child = pty_pair
         .slave
         .spawn_command(cmd)
@@ -67,19 +177,36 @@ async fn async_create_shell(shell_path: Option<String>, rows: Option<u16>, cols:
 
     let pty_id = format!("{}", std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis());
 
-    // Создаём канал для передачи данных из потока чтения
-    let (tx, rx) = mpsc::channel::<String>(1000);
-    
+    // Канал между блокирующим потоком чтения и задачей, которая эмиттит события в webview
+    let (tx, mut rx) = mpsc::channel::<String>(1000);
+
+    let current_cwd = Arc::new(Mutex::new(String::new()));
+
     // Запускаем поток чтения для этого PTY
     let mut reader = reader;
+    let current_cwd_for_reader = current_cwd.clone();
     thread::spawn(move || {
         let mut buf = [0u8; 4096];
+        let mut carry = String::new();
         loop {
             match reader.read(&mut buf) {
                 Ok(0) => break, // EOF
                 Ok(n) => {
-                    let s = String::from_utf8_lossy(&buf[..n]).into_owned();
-                    if tx.blocking_send(s).is_err() {
+                    let chunk = String::from_utf8_lossy(&buf[..n]).into_owned();
+
+                    // OSC 7 sequences can straddle two reads, so scan the carried-over tail of
+                    // the previous chunk together with this one.
+                    let mut combined = carry;
+                    combined.push_str(&chunk);
+                    let (cwd, leftover) = scan_osc7_cwd(&combined);
+                    if let Some(path) = cwd {
+                        if let Ok(mut guard) = current_cwd_for_reader.lock() {
+                            *guard = path;
+                        }
+                    }
+                    carry = leftover;
+
+                    if tx.blocking_send(chunk).is_err() {
                         break;
                     }
                 }
@@ -88,26 +215,214 @@ async fn async_create_shell(shell_path: Option<String>, rows: Option<u16>, cols:
         }
     });
 
+    // Эмиттим вывод PTY в webview по каналу `pty-data://<pty_id>`, собирая всё, что уже успело
+    // накопиться в канале, в одно событие — это убирает лишний IPC на "тяжёлом" выводе
+    // (например, cat большого файла).
+    let app_handle_for_data = state.app_handle.clone();
+    let pty_id_for_data = pty_id.clone();
+    tauri::async_runtime::spawn(async move {
+        let channel = format!("pty-data://{}", pty_id_for_data);
+        while let Some(mut data) = rx.recv().await {
+            while let Ok(more) = rx.try_recv() {
+                data.push_str(&more);
+            }
+            let event = PtyDataEvent { pty_id: pty_id_for_data.clone(), data };
+            if app_handle_for_data.emit(&channel, event).is_err() {
+                break;
+            }
+        }
+    });
+
     let pty_instance = PtyInstance {
         pty_pair,
         writer,
-        data_receiver: Arc::new(AsyncMutex::new(rx)),
+        current_cwd,
     };
 
     state.pty_instances.lock().await.insert(pty_id.clone(), pty_instance);
 
-    // Отслеживаем завершение процесса и отправляем событие
+    // Отслеживаем завершение процесса и отправляем событие с его реальным статусом
     let app_handle = state.app_handle.clone();
     let pty_id_for_event = pty_id.clone();
     thread::spawn(move || {
-        let _status = child.wait();
-        // Отправляем событие о завершении процесса
-        app_handle.emit("pty-exited", pty_id_for_event).ok();
+        let (exit_code, signal) = match child.wait() {
+            Ok(status) if status.success() => (Some(0), None),
+            Ok(status) => {
+                #[cfg(unix)]
+                let signal_name = status.signal();
+                #[cfg(not(unix))]
+                let signal_name: Option<&str> = None;
+
+                match signal_name {
+                    // Signal-terminated: no meaningful exit code, even if we can't map this
+                    // particular signal name to a number.
+                    Some(name) => (None, signal_number(name)),
+                    None => (Some(status.exit_code() as i32), None),
+                }
+            }
+            Err(_) => (None, None),
+        };
+
+        app_handle
+            .emit("pty-exited", PtyExitedEvent { pty_id: pty_id_for_event, exit_code, signal })
+            .ok();
     });
-    
+
     Ok(pty_id)
 }
 
+#[tauri::command]
+async fn async_create_shell(
+    shell_path: Option<String>,
+    shell_args: Option<Vec<String>>,
+    rows: Option<u16>,
+    cols: Option<u16>,
+    cwd: Option<String>,
+    env: Option<HashMap<String, String>>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let mut cmd = match shell_path {
+        Some(path) => CommandBuilder::new(path),
+        None => {
+            #[cfg(target_os = "windows")]
+            { CommandBuilder::new("powershell.exe") }
+            #[cfg(not(target_os = "windows"))]
+            { CommandBuilder::new("bash") }
+        }
+    };
+
+    // Extra argv for the shell binary itself, e.g. `ShellProfile.args` for a specific WSL
+    // distro (`-d <distro>`) - kept separate from `shell_path` so it's never baked into a
+    // single literal program string.
+    if let Some(shell_args) = shell_args {
+        cmd.args(shell_args);
+    }
+
+    #[cfg(target_os = "windows")]
+    cmd.env("TERM", "cygwin");
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        cmd.env("TERM", "xterm-256color");
+        // Set UTF-8 locale for proper Cyrillic and other non-ASCII character support
+        cmd.env("LANG", "en_US.UTF-8");
+        cmd.env("LC_ALL", "en_US.UTF-8");
+        // Report the cwd on every prompt via OSC 7 so async_get_pty_cwd can track `cd`.
+        // Bash honours PROMPT_COMMAND out of the box, unless the user's own config replaces it;
+        // zsh ignores PROMPT_COMMAND, so zsh users need an equivalent `precmd` hook.
+        cmd.env(
+            "PROMPT_COMMAND",
+            r#"printf '\033]7;file://%s%s\007' "$HOSTNAME" "$PWD""#,
+        );
+    }
+
+    // "Open terminal here" and project-specific profiles: start in a given directory and/or
+    // layer extra variables (NODE_ENV, a custom PATH, ...) on top of the inherited environment.
+    if let Some(cwd) = cwd {
+        cmd.cwd(cwd);
+    }
+    if let Some(env) = env {
+        for (key, value) in env {
+            cmd.env(key, value);
+        }
+    }
+
+    spawn_pty(cmd, rows, cols, &state).await
+}
+
+/// Which shell (if any) a one-shot command should be run through, and so how its argv needs to
+/// be wrapped: a Unix shell takes `-c <cmd>`, `cmd.exe` takes `/C <cmd>`, PowerShell takes
+/// `-Command <cmd>`.
+#[derive(Debug, Clone, serde::Deserialize)]
+enum Shell {
+    None,
+    Unix(String),
+    Cmd,
+    Powershell,
+}
+
+/// Builds the `CommandBuilder` for running `command` (plus `args`) as a single one-shot
+/// invocation through `shell`, so the frontend can pin a PTY to one task instead of an
+/// interactive prompt.
+fn command_for_shell(shell: &Shell, command: &str, args: &[String]) -> CommandBuilder {
+    match shell {
+        Shell::None => {
+            let mut cmd = CommandBuilder::new(command);
+            cmd.args(args);
+            cmd
+        }
+        Shell::Unix(shell_bin) => {
+            let mut cmd = CommandBuilder::new(shell_bin);
+            cmd.arg("-c");
+            cmd.arg(join_quoted(command, args, |part| {
+                shell_escape::unix::escape(part.into()).into_owned()
+            }));
+            cmd
+        }
+        Shell::Cmd => {
+            let mut cmd = CommandBuilder::new("cmd.exe");
+            cmd.arg("/C");
+            cmd.arg(join_quoted(command, args, |part| {
+                shell_escape::windows::escape(part.into()).into_owned()
+            }));
+            cmd
+        }
+        Shell::Powershell => {
+            let mut cmd = CommandBuilder::new("powershell.exe");
+            cmd.arg("-Command");
+            // A quoted string alone is just a string expression in PowerShell; the `&` call
+            // operator is what makes it run as a command.
+            cmd.arg(format!("& {}", join_quoted(command, args, powershell_escape)));
+            cmd
+        }
+    }
+}
+
+/// Joins `command` and `args` into a single string for `-c`/`/C`/`-Command`, quoting each piece
+/// with `quote`. The quoting convention has to be picked per target shell rather than relying on
+/// `shell_escape::escape`'s host-OS dispatch: that only knows POSIX `sh` and `cmd.exe` quoting,
+/// chosen by the *compiling* platform, so e.g. a Windows build spawning a WSL/git-bash `Shell::Unix`
+/// would otherwise get `cmd.exe`-style escaping fed into a POSIX `-c` string.
+fn join_quoted(command: &str, args: &[String], quote: impl Fn(&str) -> String) -> String {
+    std::iter::once(command)
+        .chain(args.iter().map(String::as_str))
+        .map(quote)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Quotes `value` for a PowerShell `-Command` string. `shell-escape` has no PowerShell-aware
+/// mode, but wrapping in single quotes is PowerShell's one truly literal quoting form - it
+/// doesn't interpolate `$variables` or backtick escapes - so the only thing left to escape is an
+/// embedded single quote, which PowerShell represents as `''`.
+fn powershell_escape(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[tauri::command]
+async fn async_create_command_shell(
+    command: String,
+    args: Option<Vec<String>>,
+    shell: Shell,
+    rows: Option<u16>,
+    cols: Option<u16>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let mut cmd = command_for_shell(&shell, &command, &args.unwrap_or_default());
+
+    #[cfg(target_os = "windows")]
+    cmd.env("TERM", "cygwin");
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        cmd.env("TERM", "xterm-256color");
+        cmd.env("LANG", "en_US.UTF-8");
+        cmd.env("LC_ALL", "en_US.UTF-8");
+    }
+
+    spawn_pty(cmd, rows, cols, &state).await
+}
+
 #[tauri::command]
 async fn async_write_to_pty(pty_id: String, data: &str, state: State<'_, AppState>) -> Result<(), String> {
     let mut instances = state.pty_instances.lock().await;
@@ -122,34 +437,6 @@ async fn async_write_to_pty(pty_id: String, data: &str, state: State<'_, AppStat
     }
 }
 
-#[tauri::command]
-async fn async_read_from_pty(pty_id: String, state: State<'_, AppState>) -> Result<Option<String>, String> {
-    // Получаем Arc на receiver, быстро освобождая глобальный mutex
-    let receiver = {
-        let instances = state.pty_instances.lock().await;
-        if let Some(instance) = instances.get(&pty_id) {
-            instance.data_receiver.clone()
-        } else {
-            return Ok(None);
-        }
-    };
-
-    // Блокируем только receiver этого терминала
-    let mut receiver = receiver.lock().await;
-    
-    // Ждём данные без таймаута - блокируемся до получения данных
-    match receiver.recv().await {
-        Some(mut data) => {
-            // Получили данные - собираем всё что есть в буфере
-            while let Ok(more) = receiver.try_recv() {
-                data.push_str(&more);
-            }
-            Ok(Some(data))
-        }
-        None => Ok(None), // Канал закрыт
-    }
-}
-
 #[tauri::command]
 async fn async_resize_pty(pty_id: String, rows: u16, cols: u16, state: State<'_, AppState>) -> Result<(), String> {
     let mut instances = state.pty_instances.lock().await;
@@ -175,7 +462,17 @@ async fn async_remove_pty(pty_id: String, state: State<'_, AppState>) -> Result<
 }
 
 #[tauri::command]
-async fn async_get_pty_cwd(_pty_id: String, _state: State<'_, AppState>) -> Result<String, String> {
+async fn async_get_pty_cwd(pty_id: String, state: State<'_, AppState>) -> Result<String, String> {
+    // Once the shell has emitted at least one OSC 7 sequence we know its real cwd; until then
+    // (or for an unknown pty_id) fall back to the user's home directory, whatever cwd the shell
+    // was started in.
+    if let Some(instance) = state.pty_instances.lock().await.get(&pty_id) {
+        let cwd = instance.current_cwd.lock().map_err(|err| err.to_string())?.clone();
+        if !cwd.is_empty() {
+            return Ok(cwd);
+        }
+    }
+
     // Получаем домашнюю директорию пользователя - это начальная директория shell
     #[cfg(target_os = "windows")]
     {
@@ -196,6 +493,61 @@ async fn async_get_pty_cwd(_pty_id: String, _state: State<'_, AppState>) -> Resu
 struct ShellProfile {
     name: String,
     path: String,
+    // Extra argv to pass to `path` (e.g. `-d <distro>` for a specific WSL install). `path` stays
+    // the literal program to exec, matching how async_create_shell spawns every other profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<Vec<String>>,
+}
+
+/// Scans `C:\Program Files\PowerShell\*` for the highest installed `pwsh.exe`, rather than only
+/// checking the hard-coded 6 and 7 directories.
+#[cfg(target_os = "windows")]
+fn pwsh_core_path() -> Option<String> {
+    use std::path::Path;
+
+    let mut entries: Vec<_> = std::fs::read_dir(Path::new(r"C:\Program Files\PowerShell"))
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .collect();
+    entries.sort_by_key(|entry| {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let major: u32 = name.chars().take_while(|c| c.is_ascii_digit()).collect::<String>().parse().unwrap_or(0);
+        (major, name)
+    });
+
+    entries.into_iter().rev().find_map(|entry| {
+        let candidate = entry.path().join("pwsh.exe");
+        candidate.exists().then(|| candidate.to_string_lossy().into_owned())
+    })
+}
+
+/// Runs `wsl.exe --list --quiet` (which writes UTF-16LE regardless of console code page) and
+/// returns one shell profile per installed distribution, with `path` set to `wsl_path` and
+/// `args` set to `-d <distro>` so a specific distro can be opened directly. `async_create_shell`
+/// execs `path` literally with no argument splitting, so the `-d <distro>` flag has to travel as
+/// separate argv entries rather than being baked into the `path` string.
+#[cfg(target_os = "windows")]
+fn wsl_distro_profiles(wsl_path: &str) -> Vec<ShellProfile> {
+    let Ok(output) = std::process::Command::new(wsl_path).args(["--list", "--quiet"]).output() else {
+        return Vec::new();
+    };
+
+    let utf16: Vec<u16> = output
+        .stdout
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+
+    String::from_utf16_lossy(&utf16)
+        .lines()
+        .map(|line| line.trim_start_matches('\u{feff}').trim())
+        .filter(|name| !name.is_empty())
+        .map(|name| ShellProfile {
+            name: format!("WSL: {}", name),
+            path: wsl_path.to_string(),
+            args: Some(vec!["-d".to_string(), name.to_string()]),
+        })
+        .collect()
 }
 
 #[tauri::command]
@@ -212,22 +564,17 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
             profiles.push(ShellProfile {
                 name: "Windows PowerShell".to_string(),
                 path: powershell_path.to_string(),
+                args: None,
             });
         }
 
-        // PowerShell Core (pwsh)
-        let pwsh_paths = [
-            r"C:\Program Files\PowerShell\7\pwsh.exe",
-            r"C:\Program Files\PowerShell\6\pwsh.exe",
-        ];
-        for path in pwsh_paths {
-            if Path::new(path).exists() {
-                profiles.push(ShellProfile {
-                    name: "PowerShell Core".to_string(),
-                    path: path.to_string(),
-                });
-                break;
-            }
+        // PowerShell Core (pwsh) - scan every installed version instead of hard-coding 6 and 7
+        if let Some(path) = pwsh_core_path() {
+            profiles.push(ShellProfile {
+                name: "PowerShell Core".to_string(),
+                path,
+                args: None,
+            });
         }
 
         // Command Prompt (cmd)
@@ -236,6 +583,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
             profiles.push(ShellProfile {
                 name: "Command Prompt".to_string(),
                 path: cmd_path.to_string(),
+                args: None,
             });
         }
 
@@ -249,18 +597,16 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
                 profiles.push(ShellProfile {
                     name: "Git Bash".to_string(),
                     path: path.to_string(),
+                    args: None,
                 });
                 break;
             }
         }
 
-        // WSL (Windows Subsystem for Linux)
+        // WSL (Windows Subsystem for Linux) - one profile per installed distribution
         let wsl_path = r"C:\Windows\System32\wsl.exe";
         if Path::new(wsl_path).exists() {
-            profiles.push(ShellProfile {
-                name: "WSL".to_string(),
-                path: wsl_path.to_string(),
-            });
+            profiles.extend(wsl_distro_profiles(wsl_path));
         }
 
         // Cygwin
@@ -269,6 +615,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
             profiles.push(ShellProfile {
                 name: "Cygwin".to_string(),
                 path: cygwin_path.to_string(),
+                args: None,
             });
         }
 
@@ -282,6 +629,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
                 profiles.push(ShellProfile {
                     name: "MSYS2".to_string(),
                     path: path.to_string(),
+                    args: None,
                 });
                 break;
             }
@@ -297,6 +645,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
             profiles.push(ShellProfile {
                 name: "Zsh".to_string(),
                 path: "/bin/zsh".to_string(),
+                args: None,
             });
         }
 
@@ -305,6 +654,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
             profiles.push(ShellProfile {
                 name: "Bash".to_string(),
                 path: "/bin/bash".to_string(),
+                args: None,
             });
         }
 
@@ -315,6 +665,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
                 profiles.push(ShellProfile {
                     name: "Fish".to_string(),
                     path: path.to_string(),
+                    args: None,
                 });
                 break;
             }
@@ -325,6 +676,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
             profiles.push(ShellProfile {
                 name: "Sh".to_string(),
                 path: "/bin/sh".to_string(),
+                args: None,
             });
         }
     }
@@ -338,6 +690,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
             profiles.push(ShellProfile {
                 name: "Bash".to_string(),
                 path: "/bin/bash".to_string(),
+                args: None,
             });
         }
 
@@ -348,6 +701,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
                 profiles.push(ShellProfile {
                     name: "Zsh".to_string(),
                     path: path.to_string(),
+                    args: None,
                 });
                 break;
             }
@@ -360,6 +714,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
                 profiles.push(ShellProfile {
                     name: "Fish".to_string(),
                     path: path.to_string(),
+                    args: None,
                 });
                 break;
             }
@@ -370,6 +725,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
             profiles.push(ShellProfile {
                 name: "Sh".to_string(),
                 path: "/bin/sh".to_string(),
+                args: None,
             });
         }
 
@@ -378,6 +734,7 @@ async fn get_shell_profiles() -> Result<Vec<ShellProfile>, String> {
             profiles.push(ShellProfile {
                 name: "Dash".to_string(),
                 path: "/bin/dash".to_string(),
+                args: None,
             });
         }
     }
@@ -441,8 +798,8 @@ fn main() {
         })
         .invoke_handler(tauri::generate_handler![
             async_create_shell,
+            async_create_command_shell,
             async_write_to_pty,
-            async_read_from_pty,
             async_resize_pty,
             async_remove_pty,
             async_get_pty_cwd,